@@ -407,6 +407,30 @@ impl Dataset {
         unsafe { GDALFlushCache(self.c_dataset) }
     }
 
+    /// Close the dataset, reporting any error raised while doing so.
+    ///
+    /// Closing a dataset flushes pending writes, which may fail (e.g. when writing to a
+    /// remote file system). Such errors are silently discarded when a [`Dataset`] is
+    /// dropped, so call this method instead when they need to be handled.
+    ///
+    /// See [`GDALClose`].
+    ///
+    /// [`GDALClose`]: https://gdal.org/api/raster_c_api.html#_CPPv49GDALClose12GDALDatasetH
+    pub fn close(self) -> Result<()> {
+        let c_dataset = self.c_dataset;
+        // The dataset is closed here, so `Drop` must not close it a second time.
+        std::mem::forget(self);
+
+        unsafe { gdal_sys::CPLErrorReset() };
+        unsafe { gdal_sys::GDALClose(c_dataset) };
+
+        let cpl_err = unsafe { gdal_sys::CPLGetLastErrorType() };
+        if cpl_err == CPLErr::CE_Failure || cpl_err == CPLErr::CE_Fatal {
+            return Err(_last_cpl_err(cpl_err));
+        }
+        Ok(())
+    }
+
     /// Creates a new Dataset by wrapping a C pointer
     ///
     /// # Safety
@@ -747,6 +771,26 @@ impl Dataset {
         Ok(transformation)
     }
 
+    /// Convert pixel/line `(px, py)` raster coordinates into georeferenced `(x, y)` coordinates,
+    /// using the [`Dataset`]'s [geo-transformation](Self::geo_transform).
+    ///
+    /// Returns an error if the dataset has no geo-transformation.
+    ///
+    /// See: [`GeoTransformEx::apply`]
+    pub fn pixel_to_world(&self, px: f64, py: f64) -> Result<(f64, f64)> {
+        Ok(self.geo_transform()?.apply(px, py))
+    }
+
+    /// Convert georeferenced `(x, y)` coordinates into pixel/line raster coordinates,
+    /// using the inverse of the [`Dataset`]'s [geo-transformation](Self::geo_transform).
+    ///
+    /// Returns an error if the dataset has no geo-transformation, or if it is not invertible.
+    ///
+    /// See: [`GeoTransformEx::invert`]
+    pub fn world_to_pixel(&self, x: f64, y: f64) -> Result<(f64, f64)> {
+        Ok(self.geo_transform()?.invert()?.apply(x, y))
+    }
+
     /// For datasources which support transactions, this creates a transaction.
     ///
     /// Because the transaction implements `DerefMut`, it can be used in place of the original
@@ -1083,8 +1127,9 @@ impl<'a> Drop for Transaction<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::fixture;
+    use crate::test_utils::{fixture, TempFixture};
     use crate::vector::{Geometry, LayerAccess};
+    use crate::DriverManager;
     use tempfile::TempPath;
 
     /// Copies the given file to a temporary file and opens it for writing. When the returned
@@ -1236,6 +1281,25 @@ mod tests {
         assert!(ds.create_layer(options).is_ok());
     }
 
+    #[test]
+    fn test_close() {
+        let tmp_file = TempFixture::empty("close.gpkg");
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let mut ds = driver.create_vector_only(&tmp_file).unwrap();
+        let mut layer = ds
+            .create_layer(LayerOptions {
+                name: "polygons",
+                ty: gdal_sys::OGRwkbGeometryType::wkbPolygon,
+                ..Default::default()
+            })
+            .unwrap();
+        layer.create_feature(polygon()).unwrap();
+        assert!(ds.close().is_ok());
+
+        let ds = Dataset::open(&tmp_file).unwrap();
+        assert_eq!(ds.layer(0).unwrap().feature_count(), 1);
+    }
+
     #[test]
     fn test_start_transaction() {
         let (_temp_path, mut ds) = open_gpkg_for_update(&fixture("poly.gpkg"));
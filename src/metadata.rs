@@ -1,3 +1,4 @@
+use crate::cpl::CslStringList;
 use crate::errors::*;
 use crate::gdal_major_object::MajorObject;
 use crate::utils::{_last_cpl_err, _last_null_pointer_err, _string, _string_array};
@@ -128,6 +129,40 @@ pub trait Metadata: MajorObject {
         Some(metadata)
     }
 
+    /// Get all the metadata values within the given `domain` as `(key, value)` pairs.
+    /// Returns an empty `Vec` if the domain is not defined.
+    ///
+    /// This is the parsed counterpart to [`Metadata::metadata_domain`], and pairs well with
+    /// [`Metadata::set_metadata`] for copying metadata between objects.
+    ///
+    /// # Arguments
+    /// * `domain` – the domain of interest. Use `""` for the default domain.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::{Dataset, Metadata};
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let dataset = Dataset::open("fixtures/labels.tif")?;
+    /// assert_eq!(
+    ///     dataset.metadata_pairs("IMAGE_STRUCTURE"),
+    ///     vec![("INTERLEAVE".to_string(), "BAND".to_string())]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn metadata_pairs(&self, domain: &str) -> Vec<(String, String)> {
+        self.metadata_domain(domain)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|keyval| {
+                keyval
+                    .split_once('=')
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+            })
+            .collect()
+    }
+
     /// Get a single metadata entry, as indicated by `key` and `domain`.
     ///
     /// # Example
@@ -193,6 +228,36 @@ pub trait Metadata: MajorObject {
         Ok(())
     }
 
+    /// Replace all metadata in given `domain` with the given `(key, value)` pairs.
+    ///
+    /// # Example
+    ///
+    /// ```rust, no_run
+    /// use gdal::{DriverManager, Metadata};
+    /// # fn main() -> gdal::errors::Result<()> {
+    /// let driver = DriverManager::get_driver_by_name("MEM")?;
+    /// let mut dataset = driver.create("", 1, 1, 1)?;
+    /// dataset.set_metadata(&[("one", "1"), ("two", "2")], "FOOBAR")?;
+    /// assert_eq!(dataset.metadata_item("two", "FOOBAR"), Some("2".to_string()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_metadata(&mut self, items: &[(&str, &str)], domain: &str) -> Result<()> {
+        let mut c_items = CslStringList::new();
+        for (key, value) in items {
+            c_items.add_string(&format!("{key}={value}"))?;
+        }
+        let c_domain = CString::new(domain)?;
+
+        let c_res = unsafe {
+            gdal_sys::GDALSetMetadata(self.gdal_object_ptr(), c_items.as_ptr(), c_domain.as_ptr())
+        };
+        if c_res != CPLErr::CE_None {
+            return Err(_last_cpl_err(c_res));
+        }
+        Ok(())
+    }
+
     /// For Datasets this sets the dataset name; normally
     /// application code should not set the "description" for
     /// GDALDatasets. For RasterBands it is actually a description
@@ -378,6 +443,33 @@ mod tests {
         assert_eq!(Some(value.to_owned()), result);
     }
 
+    #[test]
+    fn test_set_metadata() {
+        let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+        let domain = "Test_Domain";
+
+        let mut src = driver.create("", 1, 1, 1).unwrap();
+        src.set_metadata(&[("Key_1", "Value_1"), ("Key_2", "Value_2")], domain)
+            .unwrap();
+        let src_md = src.metadata_pairs(domain);
+        assert_eq!(
+            src_md,
+            vec![
+                ("Key_1".to_string(), "Value_1".to_string()),
+                ("Key_2".to_string(), "Value_2".to_string())
+            ]
+        );
+
+        let mut dst = driver.create("", 1, 1, 1).unwrap();
+        assert!(dst.metadata_pairs(domain).is_empty());
+        let items: Vec<(&str, &str)> = src_md
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        dst.set_metadata(&items, domain).unwrap();
+        assert_eq!(dst.metadata_pairs(domain), src_md);
+    }
+
     #[test]
     fn test_set_description() {
         let driver = DriverManager::get_driver_by_name("MEM").unwrap();
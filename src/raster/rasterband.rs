@@ -578,6 +578,38 @@ impl<'a> RasterBand<'a> {
         Ok(())
     }
 
+    /// Read a [`Buffer<f64>`] from this band with the band's [scale](Self::scale) and
+    /// [offset](Self::offset) applied, i.e. `pixel * scale + offset`.
+    ///
+    /// An unset scale is treated as `1.0` and an unset offset as `0.0`. Pixels equal to the
+    /// band's [nodata value](Self::no_data_value) are not scaled and come back as `f64::NAN`.
+    ///
+    /// # Arguments
+    /// * `window` - the window position from top left
+    /// * `window_size` - the window size (GDAL will interpolate data if window_size != buffer_size)
+    /// * `buffer_size` - the desired size of the 'Buffer'
+    /// * `e_resample_alg` - the resample algorithm used for the interpolation. Default: `NearestNeighbor`.
+    pub fn read_as_physical(
+        &self,
+        window: (isize, isize),
+        window_size: (usize, usize),
+        size: (usize, usize),
+        e_resample_alg: Option<ResampleAlg>,
+    ) -> Result<Buffer<f64>> {
+        let scale = self.scale().unwrap_or(1.0);
+        let offset = self.offset().unwrap_or(0.0);
+        let no_data = self.no_data_value();
+        let mut buffer = self.read_as::<f64>(window, window_size, size, e_resample_alg)?;
+        for value in buffer.data.iter_mut() {
+            *value = if Some(*value) == no_data {
+                f64::NAN
+            } else {
+                *value * scale + offset
+            };
+        }
+        Ok(buffer)
+    }
+
     /// Get actual block size (at the edges) when block size
     /// does not divide band size.
     #[cfg(any(all(major_is_2, minor_ge_2), major_ge_3))] // GDAL 2.2 .. 2.x or >= 3
@@ -626,6 +658,17 @@ impl<'a> RasterBand<'a> {
         _string(str_ptr)
     }
 
+    /// Set the unit of the rasterband, e.g. `"m"` or `"K"`.
+    /// Pass the empty string to clear the unit.
+    pub fn set_unit(&mut self, unit: &str) -> Result<()> {
+        let c_unit = CString::new(unit)?;
+        let rv = unsafe { gdal_sys::GDALSetRasterUnitType(self.c_rasterband, c_unit.as_ptr()) };
+        if rv != CPLErr::CE_None {
+            return Err(_last_cpl_err(rv));
+        }
+        Ok(())
+    }
+
     /// Read the band mask flags for a GDAL `RasterBand`.
     pub fn mask_flags(&self) -> Result<GdalMaskFlags> {
         let band_mask_flags = unsafe { gdal_sys::GDALGetMaskFlags(self.c_rasterband) };
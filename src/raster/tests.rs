@@ -7,7 +7,7 @@ use crate::raster::{
 };
 use crate::test_utils::TempFixture;
 use crate::vsi::unlink_mem_file;
-use crate::DriverManager;
+use crate::{assert_almost_eq, DriverManager};
 use std::path::Path;
 
 #[cfg(feature = "ndarray")]
@@ -299,6 +299,28 @@ fn test_geo_transform() {
     assert_eq!(dataset.geo_transform().unwrap(), transform);
 }
 
+#[test]
+fn test_pixel_to_world() {
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let mut dataset = driver.create("", 20, 10, 1).unwrap();
+    let transform = [768269., 0.5, 0., 4057292., 0., -0.5];
+    dataset.set_geo_transform(&transform).unwrap();
+
+    let (x, y) = dataset.pixel_to_world(10., 20.).unwrap();
+    assert_almost_eq(x, 768274.);
+    assert_almost_eq(y, 4057282.);
+
+    let (px, py) = dataset.world_to_pixel(x, y).unwrap();
+    assert_almost_eq(px, 10.);
+    assert_almost_eq(py, 20.);
+
+    // A transform with zero pixel size cannot be inverted.
+    dataset
+        .set_geo_transform(&[0., 0., 0., 0., 0., 0.])
+        .unwrap();
+    assert!(dataset.world_to_pixel(x, y).is_err());
+}
+
 #[test]
 fn test_get_driver_by_name() {
     let missing_driver = DriverManager::get_driver_by_name("wtf");
@@ -492,6 +514,41 @@ fn test_get_offset() {
     assert_eq!(offset, Some(12.0));
 }
 
+#[test]
+fn test_read_as_physical() {
+    let dataset = Dataset::open(fixture!("offset_scaled_tinymarble.tif")).unwrap();
+    let rasterband = dataset.rasterband(1).unwrap();
+    let scale = rasterband.scale().unwrap();
+    let offset = rasterband.offset().unwrap();
+
+    let raw = rasterband
+        .read_as::<u8>((0, 0), (1, 1), (1, 1), None)
+        .unwrap();
+    let physical = rasterband
+        .read_as_physical((0, 0), (1, 1), (1, 1), None)
+        .unwrap();
+    assert_eq!(physical.size, (1, 1));
+    assert_eq!(physical.data, vec![raw.data[0] as f64 * scale + offset]);
+
+    // nodata pixels are not scaled
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver.create("", 2, 1, 1).unwrap();
+    let mut rasterband = dataset.rasterband(1).unwrap();
+    let raster = ByteBuffer {
+        size: (2, 1),
+        data: vec![5u8, 7u8],
+    };
+    rasterband.write((0, 0), (2, 1), &raster).unwrap();
+    rasterband.set_no_data_value(Some(7.0)).unwrap();
+    rasterband.set_scale(2.0).unwrap();
+    rasterband.set_offset(1.0).unwrap();
+    let physical = rasterband
+        .read_as_physical((0, 0), (2, 1), (2, 1), None)
+        .unwrap();
+    assert_eq!(physical.data[0], 11.0);
+    assert!(physical.data[1].is_nan());
+}
+
 #[test]
 fn test_get_default_scale() {
     let dataset = Dataset::open(fixture!("tinymarble.png")).unwrap();
@@ -680,6 +737,16 @@ fn test_rasterband_unit() {
     assert_eq!(rasterband.unit(), "m".to_string());
 }
 
+#[test]
+fn test_set_rasterband_unit() {
+    let driver = DriverManager::get_driver_by_name("MEM").unwrap();
+    let dataset = driver.create("", 1, 1, 1).unwrap();
+    let mut rasterband = dataset.rasterband(1).unwrap();
+    assert!(rasterband.unit().is_empty());
+    rasterband.set_unit("mm/day").unwrap();
+    assert_eq!(rasterband.unit(), "mm/day");
+}
+
 #[test]
 fn test_color_table() {
     use crate::raster::rasterband::{ColorEntry, PaletteInterpretation};
@@ -592,6 +592,51 @@ impl SpatialRef {
         unsafe { gdal_sys::OSRGetLinearUnits(self.0, ptr::null_mut()) }
     }
 
+    /// Get the semi-major axis of the ellipsoid, in meters.
+    ///
+    /// See: [`OSRGetSemiMajor`](https://gdal.org/api/ogr_srs_api.html#_CPPv415OSRGetSemiMajor20OGRSpatialReferenceHP6OGRErr)
+    pub fn semi_major(&self) -> Result<f64> {
+        let mut err_code = OGRErr::OGRERR_NONE;
+        let value = unsafe { gdal_sys::OSRGetSemiMajor(self.0, &mut err_code) };
+        if err_code != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: err_code,
+                method_name: "OSRGetSemiMajor",
+            });
+        }
+        Ok(value)
+    }
+
+    /// Get the semi-minor axis of the ellipsoid, in meters.
+    ///
+    /// See: [`OSRGetSemiMinor`](https://gdal.org/api/ogr_srs_api.html#_CPPv415OSRGetSemiMinor20OGRSpatialReferenceHP6OGRErr)
+    pub fn semi_minor(&self) -> Result<f64> {
+        let mut err_code = OGRErr::OGRERR_NONE;
+        let value = unsafe { gdal_sys::OSRGetSemiMinor(self.0, &mut err_code) };
+        if err_code != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: err_code,
+                method_name: "OSRGetSemiMinor",
+            });
+        }
+        Ok(value)
+    }
+
+    /// Get the inverse flattening of the ellipsoid.
+    ///
+    /// See: [`OSRGetInvFlattening`](https://gdal.org/api/ogr_srs_api.html#_CPPv419OSRGetInvFlattening20OGRSpatialReferenceHP6OGRErr)
+    pub fn inverse_flattening(&self) -> Result<f64> {
+        let mut err_code = OGRErr::OGRERR_NONE;
+        let value = unsafe { gdal_sys::OSRGetInvFlattening(self.0, &mut err_code) };
+        if err_code != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: err_code,
+                method_name: "OSRGetInvFlattening",
+            });
+        }
+        Ok(value)
+    }
+
     #[inline]
     pub fn is_geographic(&self) -> bool {
         unsafe { gdal_sys::OSRIsGeographic(self.0) == 1 }
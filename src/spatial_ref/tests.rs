@@ -359,6 +359,14 @@ fn get_units_epsg4326() {
     assert_almost_eq(to_radians, 0.01745329);
 }
 
+#[test]
+fn get_ellipsoid_epsg4326() {
+    let spatial_ref = SpatialRef::from_epsg(4326).unwrap();
+    assert_almost_eq(spatial_ref.semi_major().unwrap(), 6378137.0);
+    assert_almost_eq(spatial_ref.semi_minor().unwrap(), 6356752.314245);
+    assert_almost_eq(spatial_ref.inverse_flattening().unwrap(), 298.257223563);
+}
+
 #[test]
 fn get_units_epsg2154() {
     let spatial_ref = SpatialRef::from_epsg(2154).unwrap();
@@ -0,0 +1,288 @@
+use crate::errors::*;
+use crate::spatial_ref::CoordTransform;
+use crate::vector::{Feature, Geometry, LayerAccess};
+use gdal_sys::OGRErr;
+
+/// Copy all features from the `src` layer into the `dst` layer.
+///
+/// Each feature's geometries and field values, including null values, are written to a new
+/// feature in `dst`. Fields and geometry fields are matched by name, and source fields missing
+/// from `dst` are skipped; use [`LayerAccess::create_fields_from_defn`] to replicate the source
+/// schema beforehand. If `dst` has a single geometry field, it receives the source geometry
+/// regardless of its name. Feature ids are not preserved.
+///
+/// See: [`OGR_F_SetFrom`](https://gdal.org/api/vector_c_api.html#_CPPv413OGR_F_SetFrom10OGRFeatureH10OGRFeatureHi)
+///
+/// Returns the number of features copied.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// use gdal::{Dataset, DriverManager, LayerOptions};
+/// use gdal::vector::{copy_features, LayerAccess, OGRwkbGeometryType};
+/// # fn main() -> gdal::errors::Result<()> {
+/// let ds = Dataset::open("fixtures/roads.geojson")?;
+/// let mut src = ds.layer(0)?;
+///
+/// let driver = DriverManager::get_driver_by_name("GPKG")?;
+/// let mut dst_ds = driver.create_vector_only("/tmp/roads.gpkg")?;
+/// let mut dst = dst_ds.create_layer(LayerOptions {
+///     name: "roads",
+///     srs: src.spatial_ref().as_ref(),
+///     ty: OGRwkbGeometryType::wkbLineString,
+///     ..Default::default()
+/// })?;
+/// dst.create_fields_from_defn(src.defn())?;
+///
+/// let count = copy_features(&mut src, &mut dst)?;
+/// assert_eq!(count, 21);
+/// # Ok(())
+/// # }
+/// ```
+pub fn copy_features<S: LayerAccess, D: LayerAccess>(src: &mut S, dst: &mut D) -> Result<usize> {
+    let mut count = 0;
+    for feature in src.features() {
+        copy_feature(&feature, dst)?.create(dst)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Outcome of [`copy_features_reproject`].
+#[derive(Debug)]
+pub struct ReprojectReport {
+    /// Number of features written to the destination layer.
+    pub copied: usize,
+    /// Features whose geometry could not be transformed, as `(fid, error)` pairs.
+    /// These features are not written to the destination layer.
+    pub failed: Vec<(Option<u64>, GdalError)>,
+}
+
+/// Copy all features from the `src` layer into the `dst` layer, transforming each geometry
+/// with `ct` on the way.
+///
+/// Behaves like [`copy_features`], except that a feature with a geometry that fails to
+/// transform is skipped and recorded in [`ReprojectReport::failed`] instead of aborting the
+/// copy. Errors raised while writing to `dst` still abort the copy.
+pub fn copy_features_reproject<S: LayerAccess, D: LayerAccess>(
+    src: &mut S,
+    dst: &mut D,
+    ct: &CoordTransform,
+) -> Result<ReprojectReport> {
+    let mut report = ReprojectReport {
+        copied: 0,
+        failed: Vec::new(),
+    };
+    for feature in src.features() {
+        let ft = copy_feature(&feature, dst)?;
+        if let Err(e) = transform_geometries(&ft, ct) {
+            report.failed.push((feature.fid(), e));
+            continue;
+        }
+        ft.create(dst)?;
+        report.copied += 1;
+    }
+    Ok(report)
+}
+
+/// Create a copy of `feature` that matches the definition of the `dst` layer.
+fn copy_feature<'a, D: LayerAccess>(feature: &Feature, dst: &'a D) -> Result<Feature<'a>> {
+    let ft = Feature::new(dst.defn())?;
+    let rv = unsafe { gdal_sys::OGR_F_SetFrom(ft.c_feature(), feature.c_feature(), 1) };
+    if rv != OGRErr::OGRERR_NONE {
+        return Err(GdalError::OgrError {
+            err: rv,
+            method_name: "OGR_F_SetFrom",
+        });
+    }
+    Ok(ft)
+}
+
+/// Transform every geometry of `feature` in place.
+fn transform_geometries(feature: &Feature, ct: &CoordTransform) -> Result<()> {
+    let count = unsafe { gdal_sys::OGR_F_GetGeomFieldCount(feature.c_feature()) };
+    for idx in 0..count {
+        let c_geom = unsafe { gdal_sys::OGR_F_GetGeomFieldRef(feature.c_feature(), idx) };
+        if c_geom.is_null() {
+            continue;
+        }
+        // The geometry is owned by the feature.
+        let mut geom = unsafe { Geometry::with_c_geometry(c_geom, false) };
+        geom.transform_inplace(ct)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spatial_ref::SpatialRef;
+    use crate::test_utils::{fixture, SuppressGDALErrorLog, TempFixture};
+    use crate::{Dataset, DriverManager, LayerOptions};
+    use gdal_sys::{OGRFieldType, OGRwkbGeometryType};
+
+    #[test]
+    fn test_copy_features() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let mut src = ds.layer(0).unwrap();
+
+        let tmp_file = TempFixture::empty("roads.gpkg");
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let mut dst_ds = driver.create_vector_only(&tmp_file).unwrap();
+        let mut dst = dst_ds
+            .create_layer(LayerOptions {
+                name: "roads",
+                ty: OGRwkbGeometryType::wkbLineString,
+                ..Default::default()
+            })
+            .unwrap();
+        dst.create_fields_from_defn(src.defn()).unwrap();
+
+        let count = copy_features(&mut src, &mut dst).unwrap();
+        assert_eq!(count, 21);
+        assert_eq!(dst.feature_count(), src.feature_count());
+
+        let ft = src.features().next().unwrap();
+        let highway = ft.field_as_string_by_name("highway").unwrap();
+        let copied = dst.features().next().unwrap();
+        assert_eq!(copied.field_as_string_by_name("highway").unwrap(), highway);
+        assert_eq!(copied.geometry(), ft.geometry());
+    }
+
+    #[test]
+    fn test_copy_features_preserves_unrepresentable_and_null_fields() {
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut src_ds = driver.create_vector_only("").unwrap();
+        let mut src = src_ds
+            .create_layer(LayerOptions {
+                name: "src",
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })
+            .unwrap();
+        // `FieldValue` cannot represent `OFTTime`
+        src.create_defn_fields(&[
+            ("time", OGRFieldType::OFTTime),
+            ("name", OGRFieldType::OFTString),
+        ])
+        .unwrap();
+        {
+            let ft = Feature::new(src.defn()).unwrap();
+            ft.set_field_string("time", "12:34:56").unwrap();
+            unsafe { gdal_sys::OGR_F_SetFieldNull(ft.c_feature(), 1) };
+            ft.create(&src).unwrap();
+        }
+
+        let mut dst_ds = driver.create_vector_only("").unwrap();
+        let mut dst = dst_ds
+            .create_layer(LayerOptions {
+                name: "dst",
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })
+            .unwrap();
+        dst.create_fields_from_defn(src.defn()).unwrap();
+
+        assert_eq!(copy_features(&mut src, &mut dst).unwrap(), 1);
+        let copied = dst.features().next().unwrap();
+        assert_eq!(
+            copied.field_as_string_by_name("time").unwrap(),
+            Some("12:34:56".to_string())
+        );
+        assert_ne!(
+            unsafe { gdal_sys::OGR_F_IsFieldNull(copied.c_feature(), 1) },
+            0
+        );
+    }
+
+    #[test]
+    fn test_copy_features_reproject() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let mut src = ds.layer(0).unwrap();
+        let src_extent = src.get_extent().unwrap();
+
+        let src_srs = src.spatial_ref().unwrap();
+        let dst_srs = SpatialRef::from_epsg(3857).unwrap();
+        #[cfg(major_ge_3)]
+        dst_srs.set_axis_mapping_strategy(
+            gdal_sys::OSRAxisMappingStrategy::OAMS_TRADITIONAL_GIS_ORDER,
+        );
+        let ct = CoordTransform::new(&src_srs, &dst_srs).unwrap();
+
+        let tmp_file = TempFixture::empty("roads.gpkg");
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let mut dst_ds = driver.create_vector_only(&tmp_file).unwrap();
+        let mut dst = dst_ds
+            .create_layer(LayerOptions {
+                name: "roads",
+                srs: Some(&dst_srs),
+                ty: OGRwkbGeometryType::wkbLineString,
+                ..Default::default()
+            })
+            .unwrap();
+        dst.create_fields_from_defn(src.defn()).unwrap();
+
+        let report = copy_features_reproject(&mut src, &mut dst, &ct).unwrap();
+        assert_eq!(report.copied, 21);
+        assert!(report.failed.is_empty());
+        assert_eq!(dst.feature_count(), src.feature_count());
+
+        // Web Mercator coordinates are in meters, a long way from the source degrees.
+        let dst_extent = dst.get_extent().unwrap();
+        assert!(dst_extent.MinX > 2_000_000.0 && dst_extent.MinX > src_extent.MinX);
+        assert!(dst_extent.MinY > 5_000_000.0 && dst_extent.MinY > src_extent.MinY);
+    }
+
+    #[test]
+    fn test_copy_features_reproject_failure() {
+        let src_srs = SpatialRef::from_epsg(4326).unwrap();
+        let dst_srs = SpatialRef::from_epsg(3857).unwrap();
+        #[cfg(major_ge_3)]
+        {
+            src_srs.set_axis_mapping_strategy(
+                gdal_sys::OSRAxisMappingStrategy::OAMS_TRADITIONAL_GIS_ORDER,
+            );
+            dst_srs.set_axis_mapping_strategy(
+                gdal_sys::OSRAxisMappingStrategy::OAMS_TRADITIONAL_GIS_ORDER,
+            );
+        }
+        let ct = CoordTransform::new(&src_srs, &dst_srs).unwrap();
+
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut src_ds = driver.create_vector_only("").unwrap();
+        let mut src = src_ds
+            .create_layer(LayerOptions {
+                name: "src",
+                srs: Some(&src_srs),
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })
+            .unwrap();
+        // The second point lies beyond the pole, outside the valid area of Web Mercator.
+        for (x, y) in [(10., 10.), (10., 95.), (20., 20.)] {
+            src.create_feature(Geometry::point_2d(x, y).unwrap())
+                .unwrap();
+        }
+        let failing_fid = src.features().nth(1).unwrap().fid();
+
+        let mut dst_ds = driver.create_vector_only("").unwrap();
+        let mut dst = dst_ds
+            .create_layer(LayerOptions {
+                name: "dst",
+                srs: Some(&dst_srs),
+                ty: OGRwkbGeometryType::wkbPoint,
+                ..Default::default()
+            })
+            .unwrap();
+
+        let report = {
+            let _nolog = SuppressGDALErrorLog::new();
+            copy_features_reproject(&mut src, &mut dst, &ct).unwrap()
+        };
+        assert_eq!(report.copied, 2);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, failing_fid);
+        assert!(failing_fid.is_some());
+        assert_eq!(dst.feature_count(), 2);
+    }
+}
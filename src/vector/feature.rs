@@ -78,7 +78,7 @@ impl<'a> Feature<'a> {
     ///
     /// If the field has an unsupported type, returns a [`GdalError::UnhandledFieldType`].
     ///
-    /// If the field is null, returns `None`.
+    /// If the field is null or unset, returns `None`.
     pub fn field<S: AsRef<str>>(&self, name: S) -> Result<Option<FieldValue>> {
         let idx = self.field_idx_from_name(name)?;
         self.field_from_id(idx)
@@ -89,76 +89,9 @@ impl<'a> Feature<'a> {
     ///
     /// If the field has an unhandled type, returns a [`GdalError::UnhandledFieldType`].
     ///
-    /// If the field is null, returns `None`.
+    /// If the field is null or unset, returns `None`.
     fn field_from_id(&self, field_id: i32) -> Result<Option<FieldValue>> {
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_id) } != 0 {
-            return Ok(None);
-        }
-
-        let field_defn = unsafe { gdal_sys::OGR_F_GetFieldDefnRef(self.c_feature, field_id) };
-        let field_type = unsafe { gdal_sys::OGR_Fld_GetType(field_defn) };
-        match field_type {
-            OGRFieldType::OFTString => {
-                let rv = unsafe { gdal_sys::OGR_F_GetFieldAsString(self.c_feature, field_id) };
-                Ok(Some(FieldValue::StringValue(_string(rv))))
-            }
-            OGRFieldType::OFTStringList => {
-                let rv = unsafe {
-                    let ptr = gdal_sys::OGR_F_GetFieldAsStringList(self.c_feature, field_id);
-                    _string_array(ptr)
-                };
-                Ok(Some(FieldValue::StringListValue(rv)))
-            }
-            OGRFieldType::OFTReal => {
-                let rv = unsafe { gdal_sys::OGR_F_GetFieldAsDouble(self.c_feature, field_id) };
-                Ok(Some(FieldValue::RealValue(rv)))
-            }
-            OGRFieldType::OFTRealList => {
-                let rv = unsafe {
-                    let mut len: i32 = 0;
-                    let ptr =
-                        gdal_sys::OGR_F_GetFieldAsDoubleList(self.c_feature, field_id, &mut len);
-                    slice::from_raw_parts(ptr, len as usize).to_vec()
-                };
-                Ok(Some(FieldValue::RealListValue(rv)))
-            }
-            OGRFieldType::OFTInteger => {
-                let rv = unsafe { gdal_sys::OGR_F_GetFieldAsInteger(self.c_feature, field_id) };
-                Ok(Some(FieldValue::IntegerValue(rv)))
-            }
-            OGRFieldType::OFTIntegerList => {
-                let rv = unsafe {
-                    let mut len: i32 = 0;
-                    let ptr =
-                        gdal_sys::OGR_F_GetFieldAsIntegerList(self.c_feature, field_id, &mut len);
-                    slice::from_raw_parts(ptr, len as usize).to_vec()
-                };
-                Ok(Some(FieldValue::IntegerListValue(rv)))
-            }
-            OGRFieldType::OFTInteger64 => {
-                let rv = unsafe { gdal_sys::OGR_F_GetFieldAsInteger64(self.c_feature, field_id) };
-                Ok(Some(FieldValue::Integer64Value(rv)))
-            }
-            OGRFieldType::OFTInteger64List => {
-                let rv = unsafe {
-                    let mut len: i32 = 0;
-                    let ptr =
-                        gdal_sys::OGR_F_GetFieldAsInteger64List(self.c_feature, field_id, &mut len);
-                    slice::from_raw_parts(ptr, len as usize).to_vec()
-                };
-                Ok(Some(FieldValue::Integer64ListValue(rv)))
-            }
-            OGRFieldType::OFTDateTime => Ok(Some(FieldValue::DateTimeValue(
-                self._field_as_datetime(field_id)?,
-            ))),
-            OGRFieldType::OFTDate => Ok(Some(FieldValue::DateValue(
-                self._field_as_datetime(field_id)?.date_naive(),
-            ))),
-            _ => Err(GdalError::UnhandledFieldType {
-                field_type,
-                method_name: "OGR_Fld_GetType",
-            }),
-        }
+        FieldValue::try_read(self, field_id)
     }
 
     /// Get the index of the named field.
@@ -183,7 +116,7 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
     ///
-    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(None)` if the field is null or unset.
     /// Returns `Ok(Some(0))` on other kinds of errors.
     ///
     pub fn field_as_integer(&self, field_idx: i32) -> Result<Option<i32>> {
@@ -194,7 +127,7 @@ impl<'a> Feature<'a> {
             });
         }
 
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_idx) } != 0 {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
             return Ok(None);
         }
 
@@ -207,13 +140,13 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldName`].
     ///
-    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(None)` if the field is null or unset.
     /// Returns `Ok(Some(0))` on other kinds of errors.
     ///
     pub fn field_as_integer_by_name(&self, field_name: &str) -> Result<Option<i32>> {
         let field_idx = self.field_idx_from_name(field_name)?;
 
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_idx) } != 0 {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
             return Ok(None);
         }
 
@@ -226,13 +159,13 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldName`].
     ///
-    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(None)` if the field is null or unset.
     /// Returns `Ok(Some(0))` on other kinds of errors.
     ///
     pub fn field_as_integer64_by_name(&self, field_name: &str) -> Result<Option<i64>> {
         let field_idx = self.field_idx_from_name(field_name)?;
 
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_idx) } != 0 {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
             return Ok(None);
         }
 
@@ -245,7 +178,7 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
     ///
-    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(None)` if the field is null or unset.
     /// Returns `Ok(Some(0))` on other kinds of errors.
     ///
     pub fn field_as_integer64(&self, field_idx: i32) -> Result<Option<i64>> {
@@ -256,7 +189,7 @@ impl<'a> Feature<'a> {
             });
         }
 
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_idx) } != 0 {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
             return Ok(None);
         }
 
@@ -269,13 +202,13 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldName`].
     ///
-    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(None)` if the field is null or unset.
     /// Returns `Ok(Some(0.))` on other kinds of errors.
     ///
     pub fn field_as_double_by_name(&self, field_name: &str) -> Result<Option<f64>> {
         let field_idx = self.field_idx_from_name(field_name)?;
 
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_idx) } != 0 {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
             return Ok(None);
         }
 
@@ -288,7 +221,7 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
     ///
-    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(None)` if the field is null or unset.
     /// Returns `Ok(Some(0.))` on other kinds of errors.
     ///
     pub fn field_as_double(&self, field_idx: i32) -> Result<Option<f64>> {
@@ -299,7 +232,7 @@ impl<'a> Feature<'a> {
             });
         }
 
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_idx) } != 0 {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
             return Ok(None);
         }
 
@@ -312,12 +245,12 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldName`].
     ///
-    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(None)` if the field is null or unset.
     ///
     pub fn field_as_string_by_name(&self, field_name: &str) -> Result<Option<String>> {
         let field_idx = self.field_idx_from_name(field_name)?;
 
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_idx) } != 0 {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
             return Ok(None);
         }
 
@@ -330,7 +263,7 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
     ///
-    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(None)` if the field is null or unset.
     ///
     pub fn field_as_string(&self, field_idx: i32) -> Result<Option<String>> {
         if field_idx >= self.field_count() {
@@ -340,7 +273,7 @@ impl<'a> Feature<'a> {
             });
         }
 
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_idx) } != 0 {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
             return Ok(None);
         }
 
@@ -353,7 +286,7 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldName`].
     ///
-    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(None)` if the field is null or unset.
     ///
     pub fn field_as_datetime_by_name(
         &self,
@@ -361,7 +294,7 @@ impl<'a> Feature<'a> {
     ) -> Result<Option<DateTime<FixedOffset>>> {
         let field_idx = self.field_idx_from_name(field_name)?;
 
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_idx) } != 0 {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
             return Ok(None);
         }
 
@@ -374,7 +307,7 @@ impl<'a> Feature<'a> {
     ///
     /// If the field is missing, returns [`GdalError::InvalidFieldIndex`].
     ///
-    /// Returns `Ok(None)` if the field is null.
+    /// Returns `Ok(None)` if the field is null or unset.
     ///
     pub fn field_as_datetime(&self, field_idx: i32) -> Result<Option<DateTime<FixedOffset>>> {
         if field_idx >= self.field_count() {
@@ -384,7 +317,7 @@ impl<'a> Feature<'a> {
             });
         }
 
-        if unsafe { gdal_sys::OGR_F_IsFieldNull(self.c_feature, field_idx) } != 0 {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(self.c_feature, field_idx) } == 0 {
             return Ok(None);
         }
 
@@ -727,6 +660,18 @@ impl<'a> Drop for Feature<'a> {
     }
 }
 
+/// Copy a list returned by one of the `OGR_F_GetFieldAsXxxList` functions, which return a
+/// null pointer on failure.
+///
+/// # Safety
+/// `ptr` must be null or point to `len` elements.
+unsafe fn _list_to_vec<T: Copy>(ptr: *const T, len: c_int) -> Vec<T> {
+    if ptr.is_null() || len <= 0 {
+        return Vec::new();
+    }
+    slice::from_raw_parts(ptr, len as usize).to_vec()
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum FieldValue {
     IntegerValue(i32),
@@ -742,6 +687,113 @@ pub enum FieldValue {
 }
 
 impl FieldValue {
+    /// Read the value of the field at index `idx` of `feature`, which is expected to be of
+    /// type `field_type`.
+    ///
+    /// The `OGR_F_GetFieldAsXxx` getter matching `field_type` is used to fetch the value, so
+    /// the returned variant always corresponds to `field_type`. Values are never coerced: if
+    /// `field_type` differs from the actual type of the field, `None` is returned.
+    ///
+    /// Returns `None` if the field is null or unset, if `idx` is out of range, if `field_type`
+    /// does not match the field's type, or if `field_type` is not supported.
+    pub fn read(
+        feature: &Feature,
+        idx: usize,
+        field_type: OGRFieldType::Type,
+    ) -> Option<FieldValue> {
+        let idx: i32 = idx.try_into().ok()?;
+        if idx >= feature.field_count() {
+            return None;
+        }
+        let field_defn = unsafe { gdal_sys::OGR_F_GetFieldDefnRef(feature.c_feature, idx) };
+        if unsafe { gdal_sys::OGR_Fld_GetType(field_defn) } != field_type {
+            return None;
+        }
+        Self::try_read(feature, idx).ok().flatten()
+    }
+
+    /// Read the value of the field at index `field_id` of `feature`, according to the type of
+    /// the field.
+    ///
+    /// Reports unsupported field types as a [`GdalError::UnhandledFieldType`].
+    pub(crate) fn try_read(feature: &Feature, field_id: i32) -> Result<Option<FieldValue>> {
+        if unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(feature.c_feature, field_id) } == 0 {
+            return Ok(None);
+        }
+
+        let field_defn = unsafe { gdal_sys::OGR_F_GetFieldDefnRef(feature.c_feature, field_id) };
+        let field_type = unsafe { gdal_sys::OGR_Fld_GetType(field_defn) };
+        match field_type {
+            OGRFieldType::OFTString => {
+                let rv = unsafe { gdal_sys::OGR_F_GetFieldAsString(feature.c_feature, field_id) };
+                Ok(Some(FieldValue::StringValue(_string(rv))))
+            }
+            OGRFieldType::OFTStringList => {
+                let rv = unsafe {
+                    let ptr = gdal_sys::OGR_F_GetFieldAsStringList(feature.c_feature, field_id);
+                    _string_array(ptr)
+                };
+                Ok(Some(FieldValue::StringListValue(rv)))
+            }
+            OGRFieldType::OFTReal => {
+                let rv = unsafe { gdal_sys::OGR_F_GetFieldAsDouble(feature.c_feature, field_id) };
+                Ok(Some(FieldValue::RealValue(rv)))
+            }
+            OGRFieldType::OFTRealList => {
+                let rv = unsafe {
+                    let mut len: i32 = 0;
+                    let ptr =
+                        gdal_sys::OGR_F_GetFieldAsDoubleList(feature.c_feature, field_id, &mut len);
+                    _list_to_vec(ptr, len)
+                };
+                Ok(Some(FieldValue::RealListValue(rv)))
+            }
+            OGRFieldType::OFTInteger => {
+                let rv = unsafe { gdal_sys::OGR_F_GetFieldAsInteger(feature.c_feature, field_id) };
+                Ok(Some(FieldValue::IntegerValue(rv)))
+            }
+            OGRFieldType::OFTIntegerList => {
+                let rv = unsafe {
+                    let mut len: i32 = 0;
+                    let ptr = gdal_sys::OGR_F_GetFieldAsIntegerList(
+                        feature.c_feature,
+                        field_id,
+                        &mut len,
+                    );
+                    _list_to_vec(ptr, len)
+                };
+                Ok(Some(FieldValue::IntegerListValue(rv)))
+            }
+            OGRFieldType::OFTInteger64 => {
+                let rv =
+                    unsafe { gdal_sys::OGR_F_GetFieldAsInteger64(feature.c_feature, field_id) };
+                Ok(Some(FieldValue::Integer64Value(rv)))
+            }
+            OGRFieldType::OFTInteger64List => {
+                let rv = unsafe {
+                    let mut len: i32 = 0;
+                    let ptr = gdal_sys::OGR_F_GetFieldAsInteger64List(
+                        feature.c_feature,
+                        field_id,
+                        &mut len,
+                    );
+                    _list_to_vec(ptr, len)
+                };
+                Ok(Some(FieldValue::Integer64ListValue(rv)))
+            }
+            OGRFieldType::OFTDateTime => Ok(Some(FieldValue::DateTimeValue(
+                feature._field_as_datetime(field_id)?,
+            ))),
+            OGRFieldType::OFTDate => Ok(Some(FieldValue::DateValue(
+                feature._field_as_datetime(field_id)?.date_naive(),
+            ))),
+            _ => Err(GdalError::UnhandledFieldType {
+                field_type,
+                method_name: "OGR_Fld_GetType",
+            }),
+        }
+    }
+
     /// Interpret the value as `String`. Returns `None` if the value is something else.
     pub fn into_string(self) -> Option<String> {
         match self {
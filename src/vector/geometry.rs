@@ -82,6 +82,38 @@ impl Geometry {
         ))
     }
 
+    /// Create a 2D point geometry from `x` and `y` coordinates.
+    pub fn point_2d(x: f64, y: f64) -> Result<Geometry> {
+        let mut point = Geometry::empty(OGRwkbGeometryType::wkbPoint)?;
+        point.add_point_2d((x, y));
+        Ok(point)
+    }
+
+    /// Create a 2D line string geometry from a sequence of `(x, y)` coordinates.
+    pub fn line_string(coords: &[(f64, f64)]) -> Result<Geometry> {
+        let mut line = Geometry::empty(OGRwkbGeometryType::wkbLineString)?;
+        for &coord in coords {
+            line.add_point_2d(coord);
+        }
+        Ok(line)
+    }
+
+    /// Create a 2D polygon geometry from a sequence of rings of `(x, y)` coordinates.
+    ///
+    /// The first ring is the exterior ring, any following rings are interior rings (holes).
+    /// Rings are added as given, so they should be closed by the caller.
+    pub fn polygon(rings: &[Vec<(f64, f64)>]) -> Result<Geometry> {
+        let mut polygon = Geometry::empty(OGRwkbGeometryType::wkbPolygon)?;
+        for coords in rings {
+            let mut ring = Geometry::empty(OGRwkbGeometryType::wkbLinearRing)?;
+            for &coord in coords {
+                ring.add_point_2d(coord);
+            }
+            polygon.add_geometry(ring)?;
+        }
+        Ok(polygon)
+    }
+
     /// Returns a C pointer to the wrapped Geometry
     ///
     /// # Safety
@@ -137,6 +169,47 @@ impl Geometry {
         unsafe { gdal_sys::OGR_G_AddPoint_2D(self.c_geometry(), x as c_double, y as c_double) };
     }
 
+    /// Add a point with `(x, y, z, m)` coordinates, where `m` is the measure value.
+    ///
+    /// See: [`OGR_G_AddPointZM`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_AddPointZM12OGRGeometryHdddd)
+    pub fn add_point_zm(&mut self, p: (f64, f64, f64, f64)) {
+        let (x, y, z, m) = p;
+        unsafe {
+            gdal_sys::OGR_G_AddPointZM(
+                self.c_geometry(),
+                x as c_double,
+                y as c_double,
+                z as c_double,
+                m as c_double,
+            )
+        };
+    }
+
+    /// Test if the geometry has M (measure) coordinates.
+    ///
+    /// See: [`OGR_G_IsMeasured`](https://gdal.org/api/vector_c_api.html#_CPPv416OGR_G_IsMeasured12OGRGeometryH)
+    pub fn is_measured(&self) -> bool {
+        unsafe { gdal_sys::OGR_G_IsMeasured(self.c_geometry()) != 0 }
+    }
+
+    /// Add or remove the M (measure) coordinate dimension of this geometry.
+    ///
+    /// Removing the dimension discards any existing measure values.
+    ///
+    /// See: [`OGR_G_SetMeasured`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_SetMeasured12OGRGeometryHi)
+    pub fn set_measured(&mut self, measured: bool) {
+        unsafe { gdal_sys::OGR_G_SetMeasured(self.c_geometry(), measured as c_int) };
+    }
+
+    /// Get the M (measure) coordinate of a line string or a point geometry.
+    ///
+    /// `index` is the line string vertex index, from 0 to `point_count()-1`, or `0` when a point.
+    ///
+    /// See: [`OGR_G_GetM`](https://gdal.org/api/vector_c_api.html#_CPPv410OGR_G_GetM12OGRGeometryHi)
+    pub fn get_m(&self, index: usize) -> f64 {
+        unsafe { gdal_sys::OGR_G_GetM(self.c_geometry(), index as c_int) }
+    }
+
     /// Get point coordinates from a line string or a point geometry.
     ///
     /// `index` is the line string vertex index, from 0 to `point_count()-1`, or `0` when a point.
@@ -243,6 +316,60 @@ impl Geometry {
         unsafe { gdal_sys::OGR_G_Area(self.c_geometry()) }
     }
 
+    /// Compute the geodesic area of the geometry, in square meters.
+    ///
+    /// The area is computed on the ellipsoid of the geometry's [spatial reference](Self::spatial_ref),
+    /// which must be set. Unlike [`Self::area`], no reprojection is needed for geometries in
+    /// geographic coordinates.
+    ///
+    /// Returns `Err` if the spatial reference is missing, or if the geometry is not a surface
+    /// (e.g. a `Point` or `LineString`).
+    ///
+    /// This API is new as of GDAL 3.9; returns `Err` on older versions.
+    ///
+    /// See: [`OGR_G_GeodesicArea`](https://gdal.org/api/vector_c_api.html#_CPPv418OGR_G_GeodesicArea12OGRGeometryH)
+    pub fn geodesic_area(&self) -> Result<f64> {
+        #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+        {
+            unsafe { gdal_sys::CPLErrorReset() };
+            let area = unsafe { gdal_sys::OGR_G_GeodesicArea(self.c_geometry()) };
+            if area < 0.0 {
+                return Err(crate::utils::_last_cpl_err(gdal_sys::CPLErr::CE_Failure));
+            }
+            Ok(area)
+        }
+
+        #[cfg(not(any(major_ge_4, all(major_is_3, minor_ge_9))))]
+        Err(GdalError::BadArgument(
+            "geodesic_area requires GDAL >= 3.9".into(),
+        ))
+    }
+
+    /// Compute the geodesic length of the geometry, in meters.
+    ///
+    /// The length is computed on the ellipsoid of the geometry's [spatial reference](Self::spatial_ref),
+    /// which must be set. For surfaces, this is the length of the boundary.
+    ///
+    /// This API is new as of GDAL 3.10; returns `Err` on older versions.
+    ///
+    /// See: [`OGR_G_GeodesicLength`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_GeodesicLength12OGRGeometryH)
+    pub fn geodesic_length(&self) -> Result<f64> {
+        #[cfg(any(major_ge_4, all(major_is_3, minor_ge_10)))]
+        {
+            unsafe { gdal_sys::CPLErrorReset() };
+            let length = unsafe { gdal_sys::OGR_G_GeodesicLength(self.c_geometry()) };
+            if length < 0.0 {
+                return Err(crate::utils::_last_cpl_err(gdal_sys::CPLErr::CE_Failure));
+            }
+            Ok(length)
+        }
+
+        #[cfg(not(any(major_ge_4, all(major_is_3, minor_ge_10))))]
+        Err(GdalError::BadArgument(
+            "geodesic_length requires GDAL >= 3.10".into(),
+        ))
+    }
+
     /// Computes and returns the axis-aligned 2D bounding envelope for this geometry.
     ///
     /// See: [`OGR_G_GetEnvelope`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_GetEnvelope12OGRGeometryHP11OGREnvelope)
@@ -301,6 +428,21 @@ impl Geometry {
         let p = unsafe { gdal_sys::OGR_G_IsValid(self.c_geometry()) };
         p != 0
     }
+
+    /// Test if the geometry is a ring, i.e. a closed and simple line string.
+    ///
+    /// # Notes
+    /// This function requires the GEOS library.
+    /// If OGR is built without the GEOS library, this function will always return `false`.
+    /// Check with [`VersionInfo::has_geos`][has_geos].
+    ///
+    /// See: [`OGR_G_IsRing`](https://gdal.org/api/vector_c_api.html#_CPPv412OGR_G_IsRing12OGRGeometryH)
+    ///
+    /// [has_geos]: crate::version::VersionInfo::has_geos
+    pub fn is_ring(&self) -> bool {
+        let p = unsafe { gdal_sys::OGR_G_IsRing(self.c_geometry()) };
+        p != 0
+    }
 }
 
 impl Drop for Geometry {
@@ -378,6 +520,27 @@ mod tests {
         assert_eq!(bbox.json().unwrap(), "{ \"type\": \"Polygon\", \"coordinates\": [ [ [ -27.0, 85.0 ], [ 52.0, 85.0 ], [ 52.0, 33.0 ], [ -27.0, 33.0 ], [ -27.0, 85.0 ] ] ] }");
     }
 
+    #[test]
+    fn test_create_point_2d() {
+        let point = Geometry::point_2d(1.0, 2.0).unwrap();
+        assert_eq!(point.wkt().unwrap(), "POINT (1 2)");
+    }
+
+    #[test]
+    fn test_create_line_string() {
+        let line = Geometry::line_string(&[(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)]).unwrap();
+        assert_eq!(line.wkt().unwrap(), "LINESTRING (0 0,1 1,2 0)");
+    }
+
+    #[test]
+    fn test_create_polygon() {
+        let ring = vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)];
+        let polygon = Geometry::polygon(&[ring]).unwrap();
+        assert_eq!(polygon.geometry_count(), 1);
+        assert_eq!(polygon.wkt().unwrap(), "POLYGON ((0 0,0 1,1 1,1 0,0 0))");
+        assert!(polygon.is_valid());
+    }
+
     #[test]
     #[allow(clippy::float_cmp)]
     pub fn test_area() {
@@ -393,6 +556,46 @@ mod tests {
         assert_eq!(geom.area().floor(), 25.0);
     }
 
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_9)))]
+    fn test_geodesic_area() {
+        let _nolog = SuppressGDALErrorLog::new();
+        let mut cell = Geometry::bbox(0., 0., 1., 1.).unwrap();
+        assert!(cell.geodesic_area().is_err());
+
+        cell.set_spatial_ref(SpatialRef::from_epsg(4326).unwrap());
+        // Area of a 1° x 1° cell on the WGS84 ellipsoid, north of the equator.
+        let area = cell.geodesic_area().unwrap();
+        assert!((area - 12_308_463_894.0).abs() / area < 1e-3, "{area}");
+
+        for wkt in ["POINT (0 0)", "LINESTRING (0 0,1 1)"] {
+            let mut geom = Geometry::from_wkt(wkt).unwrap();
+            geom.set_spatial_ref(SpatialRef::from_epsg(4326).unwrap());
+            assert!(geom.geodesic_area().is_err(), "{wkt}");
+        }
+    }
+
+    #[test]
+    #[cfg(not(any(major_ge_4, all(major_is_3, minor_ge_9))))]
+    fn test_geodesic_area_unsupported() {
+        let mut cell = Geometry::bbox(0., 0., 1., 1.).unwrap();
+        cell.set_spatial_ref(SpatialRef::from_epsg(4326).unwrap());
+        assert!(cell.geodesic_area().is_err());
+        assert!(cell.geodesic_length().is_err());
+    }
+
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_10)))]
+    fn test_geodesic_length() {
+        let mut line = Geometry::from_wkt("LINESTRING (0 0,1 0)").unwrap();
+        let srs = SpatialRef::from_epsg(4326).unwrap();
+        srs.set_axis_mapping_strategy(gdal_sys::OSRAxisMappingStrategy::OAMS_TRADITIONAL_GIS_ORDER);
+        line.set_spatial_ref(srs);
+        // One degree of longitude along the equator.
+        let length = line.geodesic_length().unwrap();
+        assert!((length - 111_319.49).abs() < 0.01, "{length}");
+    }
+
     #[test]
     pub fn test_is_empty() {
         let geom = Geometry::empty(::gdal_sys::OGRwkbGeometryType::wkbMultiPolygon).unwrap();
@@ -440,6 +643,30 @@ mod tests {
         assert_eq!(geom, expected);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)]
+    pub fn test_create_measured_line_string() {
+        let mut line = Geometry::empty(::gdal_sys::OGRwkbGeometryType::wkbLineString).unwrap();
+        assert!(!line.is_measured());
+        line.set_measured(true);
+        assert!(line.is_measured());
+        line.add_point_zm((0.0, 0.0, 0.0, 10.0));
+        line.add_point_zm((1.0, 1.0, 0.0, 20.5));
+        assert_eq!(line.get_m(0), 10.0);
+        assert_eq!(line.get_m(1), 20.5);
+
+        let wkt = line.iso_wkt().unwrap();
+        assert_eq!(wkt, "LINESTRING ZM (0 0 0 10,1 1 0 20.5)");
+
+        let parsed = Geometry::from_wkt(&wkt).unwrap();
+        assert!(parsed.is_measured());
+        assert_eq!(parsed.get_m(0), 10.0);
+        assert_eq!(parsed.get_m(1), 20.5);
+
+        line.set_measured(false);
+        assert!(!line.is_measured());
+    }
+
     #[test]
     pub fn test_spatial_ref() {
         let geom = Geometry::empty(::gdal_sys::OGRwkbGeometryType::wkbMultiPolygon).unwrap();
@@ -494,6 +721,24 @@ mod tests {
         assert!(!points.is_empty());
     }
 
+    #[test]
+    fn test_geometry_name() {
+        let point = Geometry::from_wkt("POINT (1 2)").unwrap();
+        assert_eq!(point.geometry_name(), "POINT");
+        let line = Geometry::from_wkt("LINESTRING (0 0,1 1)").unwrap();
+        assert_eq!(line.geometry_name(), "LINESTRING");
+        let polygon = Geometry::bbox(0., 0., 1., 1.).unwrap();
+        assert_eq!(polygon.geometry_name(), "POLYGON");
+    }
+
+    #[test]
+    fn test_is_ring() {
+        let closed = Geometry::from_wkt("LINESTRING (0 0,0 1,1 1,1 0,0 0)").unwrap();
+        assert!(closed.is_ring());
+        let open = Geometry::from_wkt("LINESTRING (0 0,0 1,1 1)").unwrap();
+        assert!(!open.is_ring());
+    }
+
     #[test]
     pub fn test_geometry_type_to_name() {
         assert_eq!(
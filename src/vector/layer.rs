@@ -1,3 +1,4 @@
+use crate::cpl::CslStringList;
 use crate::metadata::Metadata;
 use crate::spatial_ref::SpatialRef;
 use crate::utils::{_last_null_pointer_err, _string};
@@ -5,7 +6,7 @@ use crate::vector::defn::Defn;
 use crate::vector::{Envelope, Feature, FieldValue, Geometry};
 use crate::{dataset::Dataset, gdal_major_object::MajorObject};
 use gdal_sys::{self, GDALMajorObjectH, OGRErr, OGRFieldDefnH, OGRFieldType, OGRLayerH};
-use libc::c_int;
+use libc::{c_char, c_int};
 use std::mem::MaybeUninit;
 use std::ptr::null_mut;
 use std::{convert::TryInto, ffi::CString, marker::PhantomData};
@@ -296,6 +297,21 @@ pub trait LayerAccess: Sized {
         }
         Ok(())
     }
+
+    /// Create fields on this layer matching those of the given layer definition.
+    ///
+    /// Each field's name, type, width and precision are copied, making this useful for
+    /// replicating the schema of a source layer before copying features into this one.
+    fn create_fields_from_defn(&self, defn: &Defn) -> Result<()> {
+        for field in defn.fields() {
+            let fdefn = FieldDefn::new(&field.name(), field.field_type())?;
+            fdefn.set_width(field.width());
+            fdefn.set_precision(field.precision());
+            fdefn.add_to_layer(self)?;
+        }
+        Ok(())
+    }
+
     fn create_feature(&mut self, geometry: Geometry) -> Result<()> {
         let feature = Feature::new(self.defn())?;
 
@@ -460,6 +476,32 @@ pub trait LayerAccess: Sized {
         }
     }
 
+    /// Set which fields the driver may skip when reading features from this layer.
+    ///
+    /// Ignored fields are left unset on the features returned afterwards, so reading them
+    /// yields `None`. Besides attribute field names, the special names `OGR_GEOMETRY` and
+    /// `OGR_STYLE` ignore the geometry and the style string respectively.
+    ///
+    /// Passing an empty slice stops ignoring any field.
+    ///
+    /// See: [`OGR_L_SetIgnoredFields`](https://gdal.org/api/vector_c_api.html#_CPPv422OGR_L_SetIgnoredFields9OGRLayerHPPKc)
+    fn set_ignored_fields(&self, fields: &[&str]) -> Result<()> {
+        let mut names = CslStringList::new();
+        for field in fields {
+            names.add_string(field)?;
+        }
+        let rv = unsafe {
+            gdal_sys::OGR_L_SetIgnoredFields(self.c_layer(), names.as_ptr() as *mut *const c_char)
+        };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_L_SetIgnoredFields",
+            });
+        }
+        Ok(())
+    }
+
     /// Read batches of columnar [Arrow](https://arrow.apache.org/) data from OGR.
     ///
     /// Extended options are available via [`CslStringList`]. As defined in the OGR documentation for [`GetArrowStream`](https://gdal.org/api/ogrlayer_cpp.html#_CPPv4N8OGRLayer14GetArrowStreamEP16ArrowArrayStream12CSLConstList), the current options are:
@@ -499,6 +541,73 @@ pub trait LayerAccess: Sized {
 
         Ok(())
     }
+
+    /// Read batches of columnar [Arrow](https://arrow.apache.org/) data from OGR into a newly
+    /// allocated [`ArrowStream`].
+    ///
+    /// Unlike [`LayerAccess::read_arrow_stream`], which documents the supported `options`, this
+    /// manages the stream memory: the stream borrows this layer and is released when dropped.
+    /// It implements the Arrow C stream interface, so it can be handed to an Arrow
+    /// implementation such as `arrow-rs` or `arrow2` without copying the data, using
+    /// [`ArrowStream::as_mut_ptr`] or [`ArrowStream::into_raw`].
+    ///
+    /// This API is new as of GDAL 3.6.
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+    fn arrow_stream(&mut self, options: &CslStringList) -> Result<ArrowStream<'_>> {
+        let mut stream = MaybeUninit::<gdal_sys::ArrowArrayStream>::zeroed();
+        unsafe {
+            self.read_arrow_stream(stream.as_mut_ptr(), options)?;
+            Ok(ArrowStream {
+                stream: stream.assume_init(),
+                phantom: PhantomData,
+            })
+        }
+    }
+}
+
+/// A stream of columnar [Arrow](https://arrow.apache.org/) data read from a layer, created by
+/// [`LayerAccess::arrow_stream`].
+///
+/// The stream is released when dropped, unless it has been moved out with
+/// [`ArrowStream::into_raw`] or through [`ArrowStream::as_mut_ptr`].
+///
+/// This API is new as of GDAL 3.6.
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+#[derive(Debug)]
+pub struct ArrowStream<'a> {
+    stream: gdal_sys::ArrowArrayStream,
+    phantom: PhantomData<&'a mut ()>,
+}
+
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+impl<'a> ArrowStream<'a> {
+    /// Returns a pointer to the underlying `ArrowArrayStream`.
+    ///
+    /// The stream remains owned by `self`. A consumer that moves the stream out of the pointer,
+    /// such as `arrow-rs`' `FFI_ArrowArrayStream::from_raw`, must leave a released stream
+    /// behind, as required by the Arrow C stream interface.
+    pub fn as_mut_ptr(&mut self) -> *mut gdal_sys::ArrowArrayStream {
+        &mut self.stream
+    }
+
+    /// Consumes `self`, returning the underlying `ArrowArrayStream` without releasing it.
+    ///
+    /// The caller becomes responsible for calling the stream's `release` callback, and must not
+    /// use the stream after the dataset of the layer it was read from is closed.
+    pub fn into_raw(self) -> gdal_sys::ArrowArrayStream {
+        let stream = self.stream;
+        std::mem::forget(self);
+        stream
+    }
+}
+
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+impl<'a> Drop for ArrowStream<'a> {
+    fn drop(&mut self) {
+        if let Some(release) = self.stream.release {
+            unsafe { release(&mut self.stream) };
+        }
+    }
 }
 
 pub struct FeatureIterator<'a> {
@@ -645,7 +754,9 @@ impl FieldDefn {
 mod tests {
     use super::{LayerCaps::*, *};
     use crate::test_utils::{fixture, SuppressGDALErrorLog, TempFixture};
-    use crate::{assert_almost_eq, Dataset, DatasetOptions, DriverManager, GdalOpenFlags};
+    use crate::{
+        assert_almost_eq, Dataset, DatasetOptions, DriverManager, GdalOpenFlags, LayerOptions,
+    };
     use gdal_sys::OGRwkbGeometryType;
 
     fn ds_with_layer<F>(ds_name: &str, layer_name: &str, f: F)
@@ -1054,6 +1165,155 @@ mod tests {
         });
     }
 
+    /// Read the fields of the first feature of `name` with [`FieldValue::read`], as
+    /// `(field name, field type, value)`.
+    fn read_first_feature(name: &str) -> Vec<(String, OGRFieldType::Type, Option<FieldValue>)> {
+        let ds = Dataset::open(fixture(name)).unwrap();
+        let mut layer = ds.layer(0).unwrap();
+        let fields: Vec<_> = layer
+            .defn()
+            .fields()
+            .map(|f| (f.name(), f.field_type()))
+            .collect();
+        let feature = layer.features().next().unwrap();
+        fields
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (name, ty))| {
+                let value = FieldValue::read(&feature, idx, ty);
+                (name, ty, value)
+            })
+            .collect()
+    }
+
+    fn value_field_type(value: &FieldValue) -> OGRFieldType::Type {
+        match value {
+            FieldValue::IntegerValue(_) => OGRFieldType::OFTInteger,
+            FieldValue::IntegerListValue(_) => OGRFieldType::OFTIntegerList,
+            FieldValue::Integer64Value(_) => OGRFieldType::OFTInteger64,
+            FieldValue::Integer64ListValue(_) => OGRFieldType::OFTInteger64List,
+            FieldValue::StringValue(_) => OGRFieldType::OFTString,
+            FieldValue::StringListValue(_) => OGRFieldType::OFTStringList,
+            FieldValue::RealValue(_) => OGRFieldType::OFTReal,
+            FieldValue::RealListValue(_) => OGRFieldType::OFTRealList,
+            FieldValue::DateValue(_) => OGRFieldType::OFTDate,
+            FieldValue::DateTimeValue(_) => OGRFieldType::OFTDateTime,
+        }
+    }
+
+    #[test]
+    fn test_field_value_read() {
+        for name in ["soundg.json", "points_with_datetime.json", "roads.geojson"] {
+            let fields = read_first_feature(name);
+            let value = |field_name: &str| {
+                fields
+                    .iter()
+                    .find(|(name, _, _)| name == field_name)
+                    .map(|(_, _, value)| value.clone())
+                    .unwrap()
+            };
+
+            for (field_name, field_type, value) in &fields {
+                if let Some(value) = value {
+                    assert_eq!(value_field_type(value), *field_type, "{name}: {field_name}");
+                }
+            }
+
+            match name {
+                "soundg.json" => {
+                    assert_eq!(
+                        value("a_string_list"),
+                        Some(FieldValue::StringListValue(vec![
+                            "a".to_string(),
+                            "list".to_string(),
+                            "of".to_string(),
+                            "strings".to_string(),
+                        ]))
+                    );
+                    assert_eq!(
+                        value("a_real_list"),
+                        Some(FieldValue::RealListValue(vec![0.1, 0.2]))
+                    );
+                    assert_eq!(
+                        value("an_int_list"),
+                        Some(FieldValue::IntegerListValue(vec![1, 2]))
+                    );
+                    assert_eq!(
+                        value("a_long_list"),
+                        Some(FieldValue::Integer64ListValue(vec![5000000000, 6000000000]))
+                    );
+                }
+                "points_with_datetime.json" => {
+                    assert!(matches!(value("dt"), Some(FieldValue::DateTimeValue(_))));
+                    assert!(matches!(value("d"), Some(FieldValue::DateValue(_))));
+                }
+                _ => {
+                    assert_eq!(
+                        value("kind"),
+                        Some(FieldValue::StringValue("path".to_string()))
+                    );
+                    assert_eq!(value("sort_key"), Some(FieldValue::RealValue(-9.0)));
+                    assert_eq!(
+                        value("highway"),
+                        Some(FieldValue::StringValue("footway".to_string()))
+                    );
+                    // `railway` is null
+                    assert_eq!(value("railway"), None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_field_value_read_type_mismatch() {
+        with_layer("roads.geojson", |mut layer| {
+            let kind_idx = layer
+                .defn()
+                .fields()
+                .position(|f| f.name() == "kind")
+                .unwrap();
+            let field_count = layer.defn().fields().count();
+            let feature = layer.features().next().unwrap();
+
+            assert!(FieldValue::read(&feature, kind_idx, OGRFieldType::OFTString).is_some());
+            for field_type in [
+                OGRFieldType::OFTIntegerList,
+                OGRFieldType::OFTInteger64List,
+                OGRFieldType::OFTRealList,
+                OGRFieldType::OFTStringList,
+                OGRFieldType::OFTInteger,
+                OGRFieldType::OFTReal,
+            ] {
+                assert_eq!(FieldValue::read(&feature, kind_idx, field_type), None);
+            }
+
+            // out of range
+            assert_eq!(
+                FieldValue::read(&feature, field_count, OGRFieldType::OFTString),
+                None
+            );
+        });
+    }
+
+    #[test]
+    fn test_field_value_read_unsupported_type() {
+        let driver = DriverManager::get_driver_by_name("Memory").unwrap();
+        let mut ds = driver.create_vector_only("").unwrap();
+        let layer = ds.create_layer(Default::default()).unwrap();
+        layer
+            .create_defn_fields(&[("time", OGRFieldType::OFTTime)])
+            .unwrap();
+        let feature = Feature::new(layer.defn()).unwrap();
+        feature.set_field_string("time", "12:34:56").unwrap();
+
+        // `FieldValue` cannot represent `OFTTime`, so the set value reads as `None`
+        assert_ne!(
+            unsafe { gdal_sys::OGR_F_IsFieldSetAndNotNull(feature.c_feature(), 0) },
+            0
+        );
+        assert_eq!(FieldValue::read(&feature, 0, OGRFieldType::OFTTime), None);
+    }
+
     #[test]
     fn test_field_in_layer() {
         ds_with_layer("three_layer_ds.s3db", "layer_0", |mut layer| {
@@ -1248,6 +1508,39 @@ mod tests {
         fs::remove_file(fixture("output.geojson")).unwrap();
     }
 
+    #[test]
+    fn test_create_fields_from_defn() {
+        let ds = Dataset::open(fixture("roads.geojson")).unwrap();
+        let src = ds.layer(0).unwrap();
+
+        let tmp_file = TempFixture::empty("copy.gpkg");
+        let driver = DriverManager::get_driver_by_name("GPKG").unwrap();
+        let mut dst_ds = driver.create_vector_only(&tmp_file).unwrap();
+        let dst = dst_ds
+            .create_layer(LayerOptions {
+                name: "roads_copy",
+                ty: OGRwkbGeometryType::wkbLineString,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(dst.defn().fields().count(), 0);
+
+        dst.create_fields_from_defn(src.defn()).unwrap();
+
+        let src_fields = src
+            .defn()
+            .fields()
+            .map(|f| (f.name(), f.field_type(), f.width(), f.precision()))
+            .collect::<Vec<_>>();
+        let dst_fields = dst
+            .defn()
+            .fields()
+            .map(|f| (f.name(), f.field_type(), f.width(), f.precision()))
+            .collect::<Vec<_>>();
+        assert_eq!(dst_fields.len(), src_fields.len());
+        assert_eq!(dst_fields, src_fields);
+    }
+
     #[test]
     fn test_features_reset() {
         with_layer("roads.geojson", |mut layer| {
@@ -1255,6 +1548,79 @@ mod tests {
         });
     }
 
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+    fn test_arrow_stream() {
+        with_layer("roads.geojson", |mut layer| {
+            let feature_count = layer.features().count();
+            let mut stream = layer.arrow_stream(&CslStringList::new()).unwrap();
+
+            let mut row_count = 0;
+            unsafe {
+                let stream_ptr = stream.as_mut_ptr();
+                let get_next = (*stream_ptr).get_next.unwrap();
+                loop {
+                    let mut array = MaybeUninit::<gdal_sys::ArrowArray>::zeroed();
+                    assert_eq!(get_next(stream_ptr, array.as_mut_ptr()), 0);
+                    let mut array = array.assume_init();
+                    // the end of the stream is signalled by a released array
+                    match array.release {
+                        Some(release) => {
+                            row_count += array.length as usize;
+                            release(&mut array);
+                        }
+                        None => break,
+                    }
+                }
+            }
+            assert_eq!(row_count, feature_count);
+        });
+    }
+
+    #[test]
+    #[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+    fn test_arrow_stream_into_raw() {
+        with_layer("roads.geojson", |mut layer| {
+            let stream = layer.arrow_stream(&CslStringList::new()).unwrap();
+            let mut raw = stream.into_raw();
+            let release = raw.release.unwrap();
+            unsafe { release(&mut raw) };
+        });
+    }
+
+    #[test]
+    fn test_set_ignored_fields() {
+        with_layer("roads.geojson", |mut layer| {
+            layer
+                .set_ignored_fields(&["highway", "kind", "sort_key"])
+                .unwrap();
+            {
+                let feature = layer.features().next().unwrap();
+                assert!(feature.field("highway").unwrap().is_none());
+                assert!(feature.field("kind").unwrap().is_none());
+                assert_eq!(feature.field_as_string_by_name("highway").unwrap(), None);
+                assert_eq!(feature.field_as_double_by_name("sort_key").unwrap(), None);
+                assert_eq!(feature.field_as_integer_by_name("sort_key").unwrap(), None);
+                assert_eq!(
+                    feature.field_as_string_by_name("is_link").unwrap(),
+                    Some("no".to_string())
+                );
+            }
+
+            layer.set_ignored_fields(&[]).unwrap();
+            {
+                let feature = layer.features().next().unwrap();
+                assert_eq!(
+                    feature.field_as_string_by_name("highway").unwrap(),
+                    Some("footway".to_string())
+                );
+            }
+
+            let _nolog = SuppressGDALErrorLog::new();
+            assert!(layer.set_ignored_fields(&["no_such_field"]).is_err());
+        });
+    }
+
     #[test]
     fn test_set_attribute_filter() {
         with_layer("roads.geojson", |mut layer| {
@@ -64,6 +64,7 @@
 //! ```
 //!
 
+mod copy;
 mod defn;
 mod feature;
 mod geometry;
@@ -71,10 +72,13 @@ mod layer;
 mod ops;
 pub mod sql;
 
+pub use copy::{copy_features, copy_features_reproject, ReprojectReport};
 pub use defn::{Defn, Field, FieldIterator};
 pub use feature::{field_type_to_name, Feature, FieldValue, FieldValueIterator};
 pub use gdal_sys::{OGRFieldType, OGRwkbGeometryType};
 pub use geometry::{geometry_type_to_name, Geometry};
+#[cfg(any(major_ge_4, all(major_is_3, minor_ge_6)))]
+pub use layer::ArrowStream;
 pub use layer::{
     FeatureIterator, FieldDefn, Layer, LayerAccess, LayerCaps, OwnedFeatureIterator, OwnedLayer,
 };
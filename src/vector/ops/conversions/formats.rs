@@ -94,6 +94,25 @@ impl Geometry {
         Ok(wkt)
     }
 
+    /// Serialize the geometry as ISO WKT.
+    ///
+    /// Unlike [`Geometry::wkt`], this includes the `Z`, `M` or `ZM` dimension tags in the output.
+    ///
+    /// See: [`OGR_G_ExportToIsoWkt`](https://gdal.org/api/vector_c_api.html#_CPPv420OGR_G_ExportToIsoWkt12OGRGeometryHPPc)
+    pub fn iso_wkt(&self) -> Result<String> {
+        let mut c_wkt = null_mut();
+        let rv = unsafe { gdal_sys::OGR_G_ExportToIsoWkt(self.c_geometry(), &mut c_wkt) };
+        if rv != OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "OGR_G_ExportToIsoWkt",
+            });
+        }
+        let wkt = _string(c_wkt);
+        unsafe { gdal_sys::OGRFree(c_wkt as *mut c_void) };
+        Ok(wkt)
+    }
+
     /// Serializes the geometry to
     /// [WKB](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry#Well-known_binary)
     /// (Well-Known Binary) format.
@@ -141,6 +160,12 @@ mod tests {
         assert_eq!(new_geom, orig_geom);
     }
 
+    #[test]
+    pub fn test_iso_wkt() {
+        let geom = Geometry::from_wkt("POINT (1 2 3)").unwrap();
+        assert_eq!(geom.iso_wkt().unwrap(), "POINT Z (1 2 3)");
+    }
+
     #[test]
     pub fn test_geojson() {
         let json = r#"{ "type": "Point", "coordinates": [10, 20] }"#;
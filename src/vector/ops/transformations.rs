@@ -28,15 +28,9 @@ impl Geometry {
     ///
     /// See: [`OGR_G_Transform`](https://gdal.org/api/vector_c_api.html#_CPPv415OGR_G_Transform12OGRGeometryH28OGRCoordinateTransformationH)
     pub fn transform(&self, htransform: &CoordTransform) -> Result<Geometry> {
-        let new_c_geom = unsafe { gdal_sys::OGR_G_Clone(self.c_geometry()) };
-        let rv = unsafe { gdal_sys::OGR_G_Transform(new_c_geom, htransform.to_c_hct()) };
-        if rv != OGRErr::OGRERR_NONE {
-            return Err(GdalError::OgrError {
-                err: rv,
-                method_name: "OGR_G_Transform",
-            });
-        }
-        Ok(unsafe { Geometry::with_c_geometry(new_c_geom, true) })
+        let mut new_geom = self.clone();
+        new_geom.transform_inplace(htransform)?;
+        Ok(new_geom)
     }
 
     /// Transforms this geometry's coordinates into another [`SpatialRef`], mutating the [`Geometry`] in-place.
@@ -57,15 +51,9 @@ impl Geometry {
     ///
     /// See: [`OGR_G_TransformTo`](https://gdal.org/api/vector_c_api.html#_CPPv417OGR_G_TransformTo12OGRGeometryH20OGRSpatialReferenceH)
     pub fn transform_to(&self, spatial_ref: &SpatialRef) -> Result<Geometry> {
-        let new_c_geom = unsafe { gdal_sys::OGR_G_Clone(self.c_geometry()) };
-        let rv = unsafe { gdal_sys::OGR_G_TransformTo(new_c_geom, spatial_ref.to_c_hsrs()) };
-        if rv != OGRErr::OGRERR_NONE {
-            return Err(GdalError::OgrError {
-                err: rv,
-                method_name: "OGR_G_TransformTo",
-            });
-        }
-        Ok(unsafe { Geometry::with_c_geometry(new_c_geom, true) })
+        let mut new_geom = self.clone();
+        new_geom.transform_to_inplace(spatial_ref)?;
+        Ok(new_geom)
     }
 
     /// Compute the convex hull of this geometry.